@@ -0,0 +1,206 @@
+#!/usr/bin/env rust-script
+//! ```cargo
+//! [dependencies]
+//! serde_json = "1.0"
+//! serde = { version = "1.0", features = ["derive"] }
+//! ```
+//!
+//! Rust example: Comparing two layout bindings with wptest diff
+//!
+//! This demonstrates running the same layout tree and constraints against
+//! two named bindings (e.g. "old" vs "new") and inspecting where their
+//! computed geometry diverges. Useful when porting the layout algorithm:
+//! point `old_binding` at the implementation being replaced and
+//! `new_binding` at its replacement, then watch the mismatch count go to
+//! zero as the port lands.
+//!
+//! To run:
+//!   cargo install rust-script
+//!   rust-script diff_test.rs
+//!
+//! Or compile normally:
+//!   rustc diff_test.rs -o diff_test
+//!   ./diff_test
+
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Layout {
+    #[serde(rename = "type")]
+    layout_type: String,
+    style: Style,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    children: Option<Vec<Layout>>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct Style {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    display: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    justify_content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    align_items: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    width: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    height: Option<f64>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct Constraints {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_width: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_height: Option<f64>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct Epsilon {
+    #[serde(rename = "abs")]
+    absolute: f64,
+    #[serde(rename = "rel")]
+    relative: f64,
+}
+
+impl Default for Epsilon {
+    fn default() -> Self {
+        Epsilon {
+            absolute: 0.5,
+            relative: 0.001,
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+struct DiffRequest {
+    layout: Layout,
+    constraints: Constraints,
+    old_binding: String,
+    new_binding: String,
+    epsilon: Epsilon,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct Mismatch {
+    node_path: Vec<usize>,
+    field: String,
+    old_value: f64,
+    new_value: f64,
+    delta: f64,
+}
+
+#[derive(Deserialize, Debug)]
+struct DiffReport {
+    mismatches: Vec<Mismatch>,
+}
+
+fn run_diff(request: &DiffRequest) -> Result<DiffReport, Box<dyn std::error::Error>> {
+    // Serialize the diff request to JSON
+    let json_input = serde_json::to_string(request)?;
+
+    // Spawn wptest diff
+    let mut child = Command::new("wptest")
+        .arg("diff")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to spawn wptest: {}", e))?;
+
+    // Write JSON to stdin
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(json_input.as_bytes())
+            .map_err(|e| format!("Failed to write to stdin: {}", e))?;
+    }
+
+    // Wait for completion and collect output. A non-zero exit code means
+    // at least one mismatch exceeded the configured tolerance, but a hard
+    // failure (bad args, crash, panic) also exits non-zero while writing
+    // nothing parseable to stdout — check for that case, using stderr,
+    // before attempting to deserialize a report that was never written.
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("Failed to wait for wptest: {}", e))?;
+
+    if !output.status.success() && output.stdout.is_empty() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("wptest diff failed: {}", stderr).into());
+    }
+
+    let report: DiffReport = serde_json::from_slice(&output.stdout)
+        .map_err(|e| format!("Failed to parse JSON: {}", e))?;
+
+    Ok(report)
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("Diffing flexbox layout between 'old' and 'new' bindings...\n");
+
+    let request = DiffRequest {
+        layout: Layout {
+            layout_type: "container".to_string(),
+            style: Style {
+                display: Some("flex".to_string()),
+                justify_content: Some("space-between".to_string()),
+                align_items: Some("center".to_string()),
+                width: Some(600.0),
+                height: Some(100.0),
+            },
+            children: Some(vec![
+                Layout {
+                    layout_type: "container".to_string(),
+                    style: Style {
+                        display: None,
+                        justify_content: None,
+                        align_items: None,
+                        width: Some(100.0),
+                        height: Some(50.0),
+                    },
+                    children: None,
+                },
+                Layout {
+                    layout_type: "container".to_string(),
+                    style: Style {
+                        display: None,
+                        justify_content: None,
+                        align_items: None,
+                        width: Some(100.0),
+                        height: Some(50.0),
+                    },
+                    children: None,
+                },
+            ]),
+        },
+        constraints: Constraints {
+            max_width: Some(800.0),
+            max_height: Some(600.0),
+        },
+        old_binding: "old".to_string(),
+        new_binding: "new".to_string(),
+        epsilon: Epsilon::default(),
+    };
+
+    let report = run_diff(&request)?;
+
+    if report.mismatches.is_empty() {
+        println!("✓ No mismatches between 'old' and 'new' beyond tolerance");
+        return Ok(());
+    }
+
+    eprintln!("✗ {} mismatch(es) found:", report.mismatches.len());
+    for mismatch in &report.mismatches {
+        eprintln!(
+            "  node {:?}.{}: old={} new={} delta={}",
+            mismatch.node_path, mismatch.field, mismatch.old_value, mismatch.new_value, mismatch.delta
+        );
+    }
+    std::process::exit(1);
+}
@@ -69,6 +69,13 @@ struct Assertion {
     assertion_type: String,
     expression: String,
     message: String,
+    // "pass" (default), "busted", or "flaky". A "busted" assertion that
+    // fails is reported but doesn't fail the run; one that unexpectedly
+    // passes does, so the annotation gets noticed and removed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expect: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    binding: Option<String>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -76,6 +83,8 @@ struct TestResult {
     passed: usize,
     failed: usize,
     skipped: usize,
+    xpassed: usize,
+    xfailed: usize,
 }
 
 fn run_layout_test(spec: &TestSpec) -> Result<TestResult, Box<dyn std::error::Error>> {
@@ -172,18 +181,44 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         assertions: vec![
             Assertion {
                 assertion_type: "layout".to_string(),
-                expression: "getX(child(root(), 0)) == 0.0".to_string(),
+                expression: "getX(child(root(), 0)) ~= 0.0".to_string(),
                 message: "first-child-at-start".to_string(),
+                expect: None,
+                binding: None,
             },
             Assertion {
                 assertion_type: "layout".to_string(),
-                expression: "getRight(child(root(), 2)) == getWidth(root())".to_string(),
+                expression: "getRight(child(root(), 2)) ~= getWidth(root())".to_string(),
                 message: "last-child-at-end".to_string(),
+                expect: None,
+                binding: None,
             },
             Assertion {
                 assertion_type: "layout".to_string(),
-                expression: "getY(child(root(), 0)) == (getHeight(root()) - getHeight(child(root(), 0))) / 2.0".to_string(),
+                expression: "getCenterY(child(root(), 0)) ~= getCenterY(root())".to_string(),
                 message: "vertically-centered".to_string(),
+                expect: None,
+                binding: None,
+            },
+            Assertion {
+                assertion_type: "layout".to_string(),
+                expression: "contains(root(), child(root(), 0))".to_string(),
+                message: "first-child-within-root".to_string(),
+                expect: None,
+                binding: None,
+            },
+            Assertion {
+                // Known bug: the "old" binding rounds the middle child's
+                // space-between position (250) down by a pixel, so this
+                // assertion fails there and counts as xfailed. Scoped to
+                // "old": if that binding is fixed the unexpected pass fails
+                // the run; against any other binding it is an ordinary
+                // assertion.
+                assertion_type: "layout".to_string(),
+                expression: "getX(child(root(), 1)) ~= 250.0".to_string(),
+                message: "middle-child-space-between-off-by-one".to_string(),
+                expect: Some("busted".to_string()),
+                binding: Some("old".to_string()),
             },
         ],
         binding: "old".to_string(),
@@ -196,16 +231,28 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("  Passed:  {}", result.passed);
     println!("  Failed:  {}", result.failed);
     println!("  Skipped: {}", result.skipped);
+    println!("  XPassed: {} (busted assertions that unexpectedly passed)", result.xpassed);
+    println!("  XFailed: {} (busted assertions that failed as expected)", result.xfailed);
     println!();
 
+    // An xpassed assertion means a "busted" annotation is stale and the
+    // underlying bug has been fixed; that should fail loudly too.
+    if result.xpassed > 0 {
+        eprintln!(
+            "✗ Test failed: {} busted assertion(s) unexpectedly passed",
+            result.xpassed
+        );
+        std::process::exit(1);
+    }
+
     // Verify all assertions passed
     if result.failed > 0 {
         eprintln!("✗ Test failed: {} assertions failed", result.failed);
         std::process::exit(1);
     }
 
-    if result.passed != 3 {
-        eprintln!("✗ Test failed: Expected 3 passing assertions, got {}", result.passed);
+    if result.passed != 4 {
+        eprintln!("✗ Test failed: Expected 4 passing assertions, got {}", result.passed);
         std::process::exit(1);
     }
 
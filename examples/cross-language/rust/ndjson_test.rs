@@ -0,0 +1,161 @@
+#!/usr/bin/env rust-script
+//! ```cargo
+//! [dependencies]
+//! serde_json = "1.0"
+//! serde = { version = "1.0", features = ["derive"] }
+//! ```
+//!
+//! Rust example: Streaming NDJSON events from wptest eval
+//!
+//! This demonstrates `wptest eval --format ndjson`, which streams one JSON
+//! object per line as each assertion is evaluated instead of returning a
+//! single summary blob. A `suite_start` event opens the stream and a
+//! `suite_end` event closes it with totals; everything in between is an
+//! `assertion` event. This example reads the stream incrementally and
+//! renders it the way `wptest eval --format human` would: terse progress
+//! dots while running, with failures buffered and pretty-printed at the end.
+//!
+//! To run:
+//!   cargo install rust-script
+//!   rust-script ndjson_test.rs
+//!
+//! Or compile normally:
+//!   rustc ndjson_test.rs -o ndjson_test
+//!   ./ndjson_test
+
+use serde::Deserialize;
+use std::io::{BufRead, BufReader, IsTerminal, Write};
+use std::process::{Command, Stdio};
+
+#[derive(Deserialize, Debug)]
+#[serde(tag = "event", rename_all = "snake_case")]
+enum Event {
+    SuiteStart {
+        total: usize,
+    },
+    Assertion {
+        message: String,
+        expression: String,
+        outcome: String,
+        actual: f64,
+        expected: f64,
+    },
+    SuiteEnd {
+        passed: usize,
+        failed: usize,
+        skipped: usize,
+    },
+}
+
+struct Failure {
+    message: String,
+    expression: String,
+    actual: f64,
+    expected: f64,
+}
+
+fn render_human(
+    events: impl Iterator<Item = Result<Event, Box<dyn std::error::Error>>>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let color = std::io::stdout().is_terminal();
+    let mut failures = Vec::new();
+
+    for event in events {
+        match event? {
+            Event::SuiteStart { total } => {
+                println!("running {} assertions", total);
+            }
+            Event::Assertion {
+                message,
+                expression,
+                outcome,
+                actual,
+                expected,
+            } => {
+                if outcome == "pass" {
+                    print!(".");
+                } else {
+                    print!("F");
+                    failures.push(Failure {
+                        message,
+                        expression,
+                        actual,
+                        expected,
+                    });
+                }
+                std::io::stdout().flush()?;
+            }
+            Event::SuiteEnd {
+                passed,
+                failed,
+                skipped,
+            } => {
+                println!();
+                println!();
+                if !failures.is_empty() {
+                    println!("failures:");
+                    for failure in &failures {
+                        if color {
+                            println!("  \x1b[31m✗ {}\x1b[0m", failure.message);
+                        } else {
+                            println!("  ✗ {}", failure.message);
+                        }
+                        println!("    expression: {}", failure.expression);
+                        println!("    expected:   {}", failure.expected);
+                        println!("    actual:     {}", failure.actual);
+                    }
+                    println!();
+                }
+                println!("result: {} passed, {} failed, {} skipped", passed, failed, skipped);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let input = r#"{
+        "layout": {"type": "container", "style": {"width": 600.0, "height": 100.0}},
+        "constraints": {"maxWidth": 800.0, "maxHeight": 600.0},
+        "assertions": [
+            {"type": "layout", "expression": "getWidth(root()) == 600.0", "message": "root-width"}
+        ],
+        "binding": "old"
+    }"#;
+
+    let mut child = Command::new("wptest")
+        .arg("eval")
+        .arg("--format")
+        .arg("ndjson")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to spawn wptest: {}", e))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(input.as_bytes())?;
+    }
+
+    let stdout = child.stdout.take().ok_or("no stdout")?;
+    let reader = BufReader::new(stdout);
+
+    // Lazily mapped so render_human consumes events as wptest emits them,
+    // rather than buffering the whole suite before the first dot prints.
+    let events = reader.lines().map(|line| -> Result<Event, Box<dyn std::error::Error>> {
+        let line = line.map_err(|e| format!("Failed to read line from wptest: {}", e))?;
+        let event = serde_json::from_str::<Event>(&line)
+            .map_err(|e| format!("Failed to parse ndjson event {:?}: {}", line, e))?;
+        Ok(event)
+    });
+
+    render_human(events)?;
+
+    let status = child.wait()?;
+    if !status.success() {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
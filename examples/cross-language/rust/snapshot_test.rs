@@ -0,0 +1,97 @@
+#!/usr/bin/env rust-script
+//! ```cargo
+//! [dependencies]
+//! serde_json = "1.0"
+//! serde = { version = "1.0", features = ["derive"] }
+//! ```
+//!
+//! Rust example: Golden snapshot testing with wptest snapshot
+//!
+//! This demonstrates `wptest snapshot`, which serializes the entire
+//! computed geometry of a layout tree (x/y/width/height per node, in tree
+//! order, floats rounded to 4 decimals, keys sorted) to a golden JSON file
+//! instead of requiring hand-written assertion expressions. Re-running
+//! compares the fresh computation against the stored golden and prints a
+//! unified diff on mismatch; pass `--bless` (or set `UPDATE=1`) to rewrite
+//! the golden with the fresh result.
+//!
+//! To run:
+//!   cargo install rust-script
+//!   rust-script snapshot_test.rs
+//!
+//! Or compile normally:
+//!   rustc snapshot_test.rs -o snapshot_test
+//!   ./snapshot_test
+
+use std::env;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+const SPEC: &str = r#"{
+    "layout": {
+        "type": "container",
+        "style": {"display": "flex", "width": 600.0, "height": 100.0},
+        "children": [
+            {"type": "container", "style": {"width": 100.0, "height": 50.0}},
+            {"type": "container", "style": {"width": 100.0, "height": 50.0}}
+        ]
+    },
+    "constraints": {"maxWidth": 800.0, "maxHeight": 600.0},
+    "binding": "old"
+}"#;
+
+const GOLDEN_PATH: &str = "flexbox.snapshot.json";
+
+fn run_snapshot(bless: bool) -> Result<bool, Box<dyn std::error::Error>> {
+    let mut command = Command::new("wptest");
+    command.arg("snapshot").arg("--golden").arg(GOLDEN_PATH);
+    if bless {
+        command.arg("--bless");
+    }
+
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to spawn wptest: {}", e))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(SPEC.as_bytes())?;
+    }
+
+    let output = child.wait_with_output()?;
+
+    if !output.status.success() {
+        // A mismatch prints a unified diff of the two normalized JSON
+        // documents, keyed by node path, to stdout.
+        print!("{}", String::from_utf8_lossy(&output.stdout));
+        return Ok(false);
+    }
+
+    Ok(true)
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let bless = env::var("UPDATE").as_deref() == Ok("1") || env::args().any(|a| a == "--bless");
+
+    println!("Running layout snapshot against {}...\n", GOLDEN_PATH);
+    let matched = run_snapshot(bless)?;
+
+    if bless {
+        if !matched {
+            eprintln!("✗ Failed to bless golden at {}", GOLDEN_PATH);
+            std::process::exit(1);
+        }
+        println!("✓ Golden blessed at {}", GOLDEN_PATH);
+        return Ok(());
+    }
+
+    if !matched {
+        eprintln!("✗ Snapshot mismatch; re-run with UPDATE=1 (or --bless) to accept the new geometry");
+        std::process::exit(1);
+    }
+
+    println!("✓ Computed geometry matches {}", GOLDEN_PATH);
+    Ok(())
+}
@@ -0,0 +1,84 @@
+#!/usr/bin/env rust-script
+//! ```cargo
+//! [dependencies]
+//! serde_json = "1.0"
+//! serde = { version = "1.0", features = ["derive"] }
+//! ```
+//!
+//! Rust example: Iterating on specs with wptest watch
+//!
+//! This demonstrates `wptest watch <path-or-glob>`, which loads JSON test
+//! specs from disk, runs them once, then watches the spec files and
+//! re-evaluates only the ones that changed, reprinting results and keeping
+//! a running tally across iterations. Spec paths are resolved relative to
+//! the working directory at startup, so the watcher doesn't desync if the
+//! process later changes its cwd. `--filter <substring>` narrows runs down
+//! to assertions whose `message` contains the substring, which is handy
+//! for iterating on a single failing case.
+//!
+//! This example spawns `wptest watch`, reads a few lines of its streamed
+//! output to show the initial run, then terminates it — watch mode itself
+//! runs until killed, so there's no "result" to assert on the way the
+//! other examples do.
+//!
+//! To run:
+//!   cargo install rust-script
+//!   rust-script watch_test.rs
+//!
+//! Or compile normally:
+//!   rustc watch_test.rs -o watch_test
+//!   ./watch_test
+
+use std::io::{BufRead, BufReader};
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::time::Duration;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("Starting wptest watch on specs/*.json (filtering on 'vertically-centered')...\n");
+
+    let mut child = Command::new("wptest")
+        .arg("watch")
+        .arg("specs/*.json")
+        .arg("--filter")
+        .arg("vertically-centered")
+        .stdout(Stdio::piped())
+        // Discarded rather than piped: nothing here reads it, and a piped
+        // stderr that fills its OS buffer would make the child block on
+        // writing to it forever.
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| format!("Failed to spawn wptest: {}", e))?;
+
+    let stdout = child.stdout.take().ok_or("no stdout")?;
+
+    // Read lines on a background thread so a watcher that falls silent
+    // after its initial run (as designed — it only speaks again once a
+    // spec file changes) can't block this thread's read forever.
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        for line in BufReader::new(stdout).lines() {
+            match line {
+                Ok(line) => {
+                    if tx.send(line).is_err() {
+                        break;
+                    }
+                }
+                Err(_) => break,
+            }
+        }
+    });
+
+    // Watch mode keeps running after the initial pass, so we only collect
+    // the first run's worth of output here rather than waiting on the
+    // process; a 2s quiet period means the initial run has finished.
+    while let Ok(line) = rx.recv_timeout(Duration::from_secs(2)) {
+        println!("{}", line);
+    }
+
+    child.kill().ok();
+    child.wait().ok();
+
+    println!("\n(stopped watching after the initial run for this example)");
+    Ok(())
+}